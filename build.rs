@@ -14,6 +14,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     if std::env::var_os("VERGEN_GIT_SHA").is_none() {
         vergen.git_sha(false);
     }
-    vergen.fail_on_error().emit()?;
+
+    // Source tarballs have no git history, so skip the git info in that case.
+    if let Err(why) = vergen.fail_on_error().emit() {
+        println!("cargo:warning=git version info unavailable: {why}");
+    }
+
     Ok(())
 }
@@ -4,3 +4,31 @@ view = View
 welcome = Welcome to COSMIC! ✨
 page-id = Page { $num }
 git-description = Git commit {$hash} on {$date}
+settings = Settings
+restore = Restore
+cancel = Cancel
+restore-defaults = Restore defaults
+restore-defaults-title = Restore default settings?
+restore-defaults-body = All settings will be reset to their default values.
+version = Version {$version}
+always-on-top = Always on top
+always-on-top-description = Keep the window above other windows. Not supported by all compositors.
+startup-page = Startup page
+last-used-page = Last used
+restore-defaults-done = Settings restored to their defaults.
+config-save-error = Failed to save settings: {$error}
+open-url-error = Failed to open {$url}: {$error}
+pages = Pages
+title-clock = Show in window title
+title-clock-off = Nothing
+title-clock-time = Time
+title-clock-date = Date
+title-clock-date-time = Date and time
+page-1-empty = Nothing here yet. Switch pages from the navigation bar or the View menu.
+page-2-empty = Nothing here yet. Open Settings from the View menu to choose the startup page.
+page-3-empty = Nothing here yet. Open Settings from the View menu to hide pages you don't use.
+general = General
+window = Window
+search-settings = Search settings
+header-date = Show date in header bar
+no-matching-settings = No matching settings
@@ -1,17 +1,23 @@
 // SPDX-License-Identifier: {{ license }}
 
-use crate::config::Config;
-use crate::fl;
+use crate::config::{Config, Page, TitleClock};
+use crate::{fl, i18n};
+use chrono::{DateTime, Local};
 use cosmic::app::{context_drawer, Core, Task};
 use cosmic::cosmic_config::{self, CosmicConfigEntry};
 use cosmic::iced::alignment::{Horizontal, Vertical};
-use cosmic::iced::{Alignment, Length, Subscription};
+use cosmic::iced::keyboard::{self, Key, Modifiers};
+use cosmic::iced::{window, Alignment, Length, Subscription};
 use cosmic::widget::{self, icon, menu, nav_bar};
 use cosmic::{cosmic_theme, theme, Application, ApplicationExt, Apply, Element};
 use futures_util::SinkExt;
 use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 const REPOSITORY: &str = env!("CARGO_PKG_REPOSITORY");
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+/// How long a status message is shown before it is dismissed automatically.
+const STATUS_TIMEOUT: Duration = Duration::from_secs(5);
 const APP_ICON: &[u8] = include_bytes!("../resources/icons/hicolor/scalable/apps/icon.svg");
 
 /// The application model stores app-specific state used to describe its interface and
@@ -21,10 +27,24 @@ pub struct AppModel {
     core: Core,
     /// Display a context drawer with the designated page if defined.
     context_page: ContextPage,
+    /// Display a dialog with the designated page if defined.
+    dialog_page: Option<DialogPage>,
     /// Contains items assigned to the nav bar panel.
     nav: nav_bar::Model,
+    /// Search query used to filter the settings page.
+    settings_search: String,
+    /// Labels for the startup page options in the settings page.
+    startup_pages: Vec<String>,
+    /// Labels for the window title clock options in the settings page.
+    title_clocks: Vec<String>,
     /// Key bindings for the application's menu bar.
     key_binds: HashMap<menu::KeyBind, MenuAction>,
+    /// The window title that was set most recently.
+    window_title: String,
+    /// Status message shown at the bottom of the window, and when it was shown.
+    status: Option<(String, StatusLevel, Instant)>,
+    /// Handle to the config context used to write changes to disk.
+    config_handler: Option<cosmic_config::Config>,
     // Configuration data that persists between application runs.
     config: Config,
 }
@@ -32,7 +52,22 @@ pub struct AppModel {
 /// Messages emitted by the application and its widgets.
 #[derive(Debug, Clone)]
 pub enum Message {
+    ClockTick,
+    DateTick,
+    DialogCancel,
+    DialogComplete,
+    DismissStatus,
+    Key(Modifiers, Key),
     OpenRepositoryUrl,
+    RestoreDefaults,
+    SelectPage(Page),
+    SetAlwaysOnTop(bool),
+    SetHeaderDate(bool),
+    SetPageVisible(Page, bool),
+    SetStartupPage(usize),
+    SetTitleClock(usize),
+    SettingsSearch(String),
+    StatusTick,
     SubscriptionChannel,
     ToggleContextPage(ContextPage),
     UpdateConfig(Config),
@@ -63,48 +98,53 @@ impl Application for AppModel {
 
     /// Initializes the application with any given flags and startup commands.
     fn init(core: Core, _flags: Self::Flags) -> (Self, Task<Self::Message>) {
-        // Create a nav bar with three page items.
-        let mut nav = nav_bar::Model::default();
-
-        nav.insert()
-            .text(fl!("page-id", num = 1))
-            .data::<Page>(Page::Page1)
-            .icon(icon::from_name("applications-science-symbolic"))
-            .activate();
-
-        nav.insert()
-            .text(fl!("page-id", num = 2))
-            .data::<Page>(Page::Page2)
-            .icon(icon::from_name("applications-system-symbolic"));
+        // Optional configuration file for an application.
+        let config_handler = cosmic_config::Config::new(Self::APP_ID, Config::VERSION).ok();
+
+        let config = config_handler
+            .as_ref()
+            .map(|context| match Config::get_entry(context) {
+                Ok(config) => config,
+                Err((_errors, config)) => {
+                    // for why in errors {
+                    //     tracing::error!(%why, "error loading app config");
+                    // }
 
-        nav.insert()
-            .text(fl!("page-id", num = 3))
-            .data::<Page>(Page::Page3)
-            .icon(icon::from_name("applications-games-symbolic"));
+                    config
+                }
+            })
+            .unwrap_or_default();
 
         // Construct the app model with the runtime's core.
         let mut app = AppModel {
             core,
             context_page: ContextPage::default(),
-            nav,
-            key_binds: HashMap::new(),
-            // Optional configuration file for an application.
-            config: cosmic_config::Config::new(Self::APP_ID, Config::VERSION)
-                .map(|context| match Config::get_entry(&context) {
-                    Ok(config) => config,
-                    Err((_errors, config)) => {
-                        // for why in errors {
-                        //     tracing::error!(%why, "error loading app config");
-                        // }
-
-                        config
-                    }
-                })
-                .unwrap_or_default(),
+            dialog_page: None,
+            nav: nav_bar::Model::default(),
+            settings_search: String::new(),
+            startup_pages: std::iter::once(fl!("last-used-page"))
+                .chain(Page::ALL.map(Page::title))
+                .collect(),
+            title_clocks: TitleClock::ALL.map(TitleClock::title).to_vec(),
+            key_binds: key_binds(),
+            window_title: String::new(),
+            status: None,
+            config_handler,
+            config,
         };
 
-        // Create a startup command that sets the window title.
-        let command = app.update_title();
+        // Create a nav bar with an item for each visible page.
+        app.rebuild_nav();
+
+        // Open the configured startup page, or else the page that was last used.
+        let startup_page = app.config.startup_page.or(app.config.last_page);
+
+        if let Some(id) = startup_page.and_then(|page| app.page_id(page)) {
+            app.nav.activate(id);
+        }
+
+        // Create startup commands that set the window title and level.
+        let command = Task::batch([app.update_title(), app.update_window_level()]);
 
         (app, command)
     }
@@ -115,13 +155,35 @@ impl Application for AppModel {
             menu::root(fl!("view")),
             menu::items(
                 &self.key_binds,
-                vec![menu::Item::Button(fl!("about"), None, MenuAction::About)],
+                Page::ALL
+                    .into_iter()
+                    .filter(|&page| self.page_id(page).is_some())
+                    .map(|page| {
+                        menu::Item::Button(page.title(), None, MenuAction::SelectPage(page))
+                    })
+                    .chain([
+                        menu::Item::Divider,
+                        menu::Item::Button(fl!("settings"), None, MenuAction::Settings),
+                        menu::Item::Button(fl!("about"), None, MenuAction::About),
+                    ])
+                    .collect(),
             ),
         )]);
 
         vec![menu_bar.into()]
     }
 
+    /// Elements to pack at the end of the header bar.
+    fn header_end(&self) -> Vec<Element<Self::Message>> {
+        if !self.config.header_date {
+            return Vec::new();
+        }
+
+        let date = i18n::format_date(&Local::now());
+
+        vec![widget::text::heading(date).into()]
+    }
+
     /// Enables the COSMIC application to create a nav bar with this model.
     fn nav_model(&self) -> Option<&nav_bar::Model> {
         Some(&self.nav)
@@ -139,20 +201,62 @@ impl Application for AppModel {
                 Message::ToggleContextPage(ContextPage::About),
             )
             .title(fl!("about")),
+
+            ContextPage::Settings => context_drawer::context_drawer(
+                self.settings(),
+                Message::ToggleContextPage(ContextPage::Settings),
+            )
+            .title(fl!("settings")),
         })
     }
 
+    /// Display a dialog if one has been requested.
+    fn dialog(&self) -> Option<Element<Self::Message>> {
+        let dialog_page = self.dialog_page.as_ref()?;
+
+        let dialog = match dialog_page {
+            DialogPage::RestoreDefaults => widget::dialog()
+                .title(fl!("restore-defaults-title"))
+                .body(fl!("restore-defaults-body"))
+                .primary_action(
+                    widget::button::destructive(fl!("restore")).on_press(Message::DialogComplete),
+                )
+                .secondary_action(
+                    widget::button::standard(fl!("cancel")).on_press(Message::DialogCancel),
+                ),
+        };
+
+        Some(dialog.into())
+    }
+
     /// Describes the interface based on the current state of the application model.
     ///
     /// Application events will be processed through the view. Any messages emitted by
     /// events received by widgets will be passed to the update method.
     fn view(&self) -> Element<Self::Message> {
-        widget::text::title1(fl!("welcome"))
+        let cosmic_theme::Spacing { space_s, .. } = theme::active().cosmic().spacing;
+
+        // Empty state for the active page, shown until it has content of its own.
+        let content: Element<_> = match self.nav.active_data::<Page>() {
+            Some(&page) => widget::column()
+                .push(icon::from_name(page.icon_name()).size(64).icon())
+                .push(widget::text::body(page.empty_state()))
+                .align_x(Alignment::Center)
+                .spacing(space_s)
+                .into(),
+            None => widget::text::title1(fl!("welcome")).into(),
+        };
+
+        let content = content
             .apply(widget::container)
             .width(Length::Fill)
             .height(Length::Fill)
             .align_x(Horizontal::Center)
-            .align_y(Vertical::Center)
+            .align_y(Vertical::Center);
+
+        widget::column()
+            .push(content)
+            .push_maybe(self.status_bar())
             .into()
     }
 
@@ -164,7 +268,7 @@ impl Application for AppModel {
     fn subscription(&self) -> Subscription<Self::Message> {
         struct MySubscription;
 
-        Subscription::batch(vec![
+        let mut subscriptions = vec![
             // Create a subscription which emits updates through a channel.
             Subscription::run_with_id(
                 std::any::TypeId::of::<MySubscription>(),
@@ -184,7 +288,32 @@ impl Application for AppModel {
 
                     Message::UpdateConfig(update.config)
                 }),
-        ])
+            // Key presses not captured by a focused widget, such as a text input.
+            keyboard::on_key_press(|key, modifiers| Some(Message::Key(modifiers, key))),
+        ];
+
+        // Keep the clock in the window title up to date.
+        if self.config.title_clock != TitleClock::Off {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_secs(1)).map(|_| Message::ClockTick),
+            );
+        }
+
+        // Redraw the header bar so that the date rolls over soon after midnight.
+        if self.config.header_date {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_secs(60)).map(|_| Message::DateTick),
+            );
+        }
+
+        // Check periodically whether the status message has expired.
+        if self.status.is_some() {
+            subscriptions.push(
+                cosmic::iced::time::every(Duration::from_secs(1)).map(|_| Message::StatusTick),
+            );
+        }
+
+        Subscription::batch(subscriptions)
     }
 
     /// Handles messages emitted by the application and its widgets.
@@ -193,8 +322,149 @@ impl Application for AppModel {
     /// on the application's async runtime.
     fn update(&mut self, message: Self::Message) -> Task<Self::Message> {
         match message {
+            Message::ClockTick => {
+                return self.update_title();
+            }
+
+            Message::DateTick => {
+                // The header bar is redrawn after each message is handled.
+            }
+
+            Message::DialogCancel => {
+                self.dialog_page = None;
+            }
+
+            Message::DialogComplete => {
+                if let Some(dialog_page) = self.dialog_page.take() {
+                    match dialog_page {
+                        DialogPage::RestoreDefaults => {
+                            let config = Config::default();
+
+                            if let Some(handler) = self.config_handler.as_ref() {
+                                if let Err(why) = config.write_entry(handler) {
+                                    self.config_save_failed(why);
+                                    return Task::none();
+                                }
+                            }
+
+                            self.show_status(StatusLevel::Info, fl!("restore-defaults-done"));
+
+                            return self.update(Message::UpdateConfig(config));
+                        }
+                    }
+                }
+            }
+
+            Message::DismissStatus => {
+                self.status = None;
+            }
+
+            Message::Key(modifiers, key) => {
+                for (key_bind, action) in &self.key_binds {
+                    if key_bind.matches(modifiers, &key) {
+                        return self.update(menu::action::MenuAction::message(action));
+                    }
+                }
+            }
+
             Message::OpenRepositoryUrl => {
-                _ = open::that_detached(REPOSITORY);
+                return self.update(Message::LaunchUrl(REPOSITORY.to_owned()));
+            }
+
+            Message::RestoreDefaults => {
+                self.dialog_page = Some(DialogPage::RestoreDefaults);
+            }
+
+            Message::SelectPage(page) => {
+                if let Some(id) = self.page_id(page) {
+                    return self.on_nav_select(id);
+                }
+            }
+
+            Message::SetAlwaysOnTop(always_on_top) => {
+                if let Some(handler) = self.config_handler.as_ref() {
+                    if let Err(why) = self.config.set_always_on_top(handler, always_on_top) {
+                        self.config_save_failed(why);
+                    }
+                } else {
+                    self.config.always_on_top = always_on_top;
+                }
+
+                return self.update_window_level();
+            }
+
+            Message::SetHeaderDate(header_date) => {
+                if let Some(handler) = self.config_handler.as_ref() {
+                    if let Err(why) = self.config.set_header_date(handler, header_date) {
+                        self.config_save_failed(why);
+                    }
+                } else {
+                    self.config.header_date = header_date;
+                }
+            }
+
+            Message::SetPageVisible(page, visible) => {
+                let mut hidden_pages = self.config.hidden_pages.clone();
+                hidden_pages.retain(|&p| p != page);
+
+                if !visible {
+                    hidden_pages.push(page);
+                }
+
+                // At least one page must remain visible.
+                if Page::ALL.iter().all(|p| hidden_pages.contains(p)) {
+                    return Task::none();
+                }
+
+                if let Some(handler) = self.config_handler.as_ref() {
+                    if let Err(why) = self.config.set_hidden_pages(handler, hidden_pages) {
+                        self.config_save_failed(why);
+                    }
+                } else {
+                    self.config.hidden_pages = hidden_pages;
+                }
+
+                self.rebuild_nav();
+                return self.update_title();
+            }
+
+            Message::SetStartupPage(index) => {
+                // The first option opens the page that was last used.
+                let startup_page = index.checked_sub(1).and_then(|i| Page::ALL.get(i).copied());
+
+                if let Some(handler) = self.config_handler.as_ref() {
+                    if let Err(why) = self.config.set_startup_page(handler, startup_page) {
+                        self.config_save_failed(why);
+                    }
+                } else {
+                    self.config.startup_page = startup_page;
+                }
+            }
+
+            Message::SetTitleClock(index) => {
+                let title_clock = TitleClock::ALL.get(index).copied().unwrap_or_default();
+
+                if let Some(handler) = self.config_handler.as_ref() {
+                    if let Err(why) = self.config.set_title_clock(handler, title_clock) {
+                        self.config_save_failed(why);
+                    }
+                } else {
+                    self.config.title_clock = title_clock;
+                }
+
+                return self.update_title();
+            }
+
+            Message::SettingsSearch(query) => {
+                self.settings_search = query;
+            }
+
+            Message::StatusTick => {
+                if let Some((_, _, shown)) = self.status {
+                    if shown.elapsed() >= STATUS_TIMEOUT {
+                        self.status = None;
+                    }
+                }
             }
 
             Message::SubscriptionChannel => {
@@ -213,13 +483,39 @@ impl Application for AppModel {
             }
 
             Message::UpdateConfig(config) => {
+                let level_changed = self.config.always_on_top != config.always_on_top;
+                let pages_changed = self.config.hidden_pages != config.hidden_pages;
+                let title_changed = self.config.title_clock != config.title_clock;
                 self.config = config;
+
+                let mut tasks = Vec::new();
+
+                if level_changed {
+                    tasks.push(self.update_window_level());
+                }
+
+                if pages_changed {
+                    self.rebuild_nav();
+                }
+
+                if pages_changed || title_changed {
+                    tasks.push(self.update_title());
+                }
+
+                return Task::batch(tasks);
             }
 
             Message::LaunchUrl(url) => match open::that_detached(&url) {
                 Ok(()) => {}
-                Err(err) => {
-                    eprintln!("failed to open {url:?}: {err}");
+                Err(why) => {
+                    self.show_status(
+                        StatusLevel::Error,
+                        fl!(
+                            "open-url-error",
+                            url = url.as_str(),
+                            error = why.to_string()
+                        ),
+                    );
                 }
             },
         }
@@ -231,6 +527,15 @@ impl Application for AppModel {
         // Activate the page in the model.
         self.nav.activate(id);
 
+        // Remember the page so that it may be reopened on the next launch.
+        let page = self.nav.data::<Page>(id).copied();
+
+        if let (Some(handler), Some(page)) = (self.config_handler.as_ref(), page) {
+            if let Err(why) = self.config.set_last_page(handler, Some(page)) {
+                self.config_save_failed(why);
+            }
+        }
+
         self.update_title()
     }
 }
@@ -244,32 +549,261 @@ impl AppModel {
 
         let title = widget::text::title3(fl!("app-title"));
 
-        let hash = env!("VERGEN_GIT_SHA");
-        let short_hash: String = hash.chars().take(7).collect();
-        let date = env!("VERGEN_GIT_COMMIT_DATE");
-
         let link = widget::button::link(REPOSITORY)
             .on_press(Message::OpenRepositoryUrl)
             .padding(0);
 
-        widget::column()
-            .push(icon)
-            .push(title)
-            .push(link)
-            .push(
+        // Git info is only available when built from a git checkout.
+        let description: Element<_> = match (
+            option_env!("VERGEN_GIT_SHA"),
+            option_env!("VERGEN_GIT_COMMIT_DATE"),
+        ) {
+            (Some(hash), Some(date)) => {
+                let short_hash: String = hash.chars().take(7).collect();
+
                 widget::button::link(fl!(
                     "git-description",
                     hash = short_hash.as_str(),
                     date = date
                 ))
                 .on_press(Message::LaunchUrl(format!("{REPOSITORY}/commits/{hash}")))
-                .padding(0),
-            )
+                .padding(0)
+                .into()
+            }
+
+            _ => widget::text::body(fl!("version", version = VERSION)).into(),
+        };
+
+        widget::column()
+            .push(icon)
+            .push(title)
+            .push(link)
+            .push(description)
             .align_x(Alignment::Center)
             .spacing(space_xxs)
             .into()
     }
 
+    /// The settings page for this app.
+    ///
+    /// Only the settings whose label, description, or section title matches the search
+    /// query are shown.
+    pub fn settings(&self) -> Element<Message> {
+        let general = vec![
+            (
+                fl!("startup-page"),
+                widget::settings::item(
+                    fl!("startup-page"),
+                    widget::dropdown(
+                        &self.startup_pages,
+                        Some(self.startup_page_index()),
+                        Message::SetStartupPage,
+                    ),
+                )
+                .into(),
+            ),
+            (
+                fl!("restore-defaults"),
+                widget::settings::item(
+                    fl!("restore-defaults"),
+                    widget::button::destructive(fl!("restore")).on_press(Message::RestoreDefaults),
+                )
+                .into(),
+            ),
+        ];
+
+        let window = vec![
+            (
+                [fl!("always-on-top"), fl!("always-on-top-description")].join(" "),
+                widget::settings::item::builder(fl!("always-on-top"))
+                    .description(fl!("always-on-top-description"))
+                    .toggler(self.config.always_on_top, Message::SetAlwaysOnTop)
+                    .into(),
+            ),
+            (
+                fl!("title-clock"),
+                widget::settings::item(
+                    fl!("title-clock"),
+                    widget::dropdown(
+                        &self.title_clocks,
+                        TitleClock::ALL
+                            .iter()
+                            .position(|&title_clock| title_clock == self.config.title_clock),
+                        Message::SetTitleClock,
+                    ),
+                )
+                .into(),
+            ),
+            (
+                fl!("header-date"),
+                widget::settings::item::builder(fl!("header-date"))
+                    .toggler(self.config.header_date, Message::SetHeaderDate)
+                    .into(),
+            ),
+        ];
+
+        let pages = Page::ALL
+            .into_iter()
+            .map(|page| {
+                let item = widget::settings::item::builder(page.title())
+                    .toggler(!self.config.hidden_pages.contains(&page), move |visible| {
+                        Message::SetPageVisible(page, visible)
+                    });
+
+                (page.title(), item.into())
+            })
+            .collect();
+
+        // Each setting is paired with the text that the search query is matched against.
+        let sections: [(String, Vec<(String, Element<Message>)>); 3] = [
+            (fl!("general"), general),
+            (fl!("window"), window),
+            (fl!("pages"), pages),
+        ];
+
+        let query = self.settings_search.trim().to_lowercase();
+
+        let search = widget::search_input(fl!("search-settings"), &self.settings_search)
+            .on_input(Message::SettingsSearch)
+            .on_clear(Message::SettingsSearch(String::new()));
+
+        let sections: Vec<Element<Message>> = sections
+            .into_iter()
+            .filter_map(|(title, items)| {
+                // Show every setting in a section whose title matches.
+                let title_matches = title.to_lowercase().contains(&query);
+
+                let items: Vec<_> = items
+                    .into_iter()
+                    .filter(|(text, _)| title_matches || text.to_lowercase().contains(&query))
+                    .map(|(_, item)| item)
+                    .collect();
+
+                // Hide sections with no matching settings.
+                if items.is_empty() {
+                    return None;
+                }
+
+                let section = items
+                    .into_iter()
+                    .fold(widget::settings::section().title(title), |section, item| {
+                        section.add(item)
+                    });
+
+                Some(section.into())
+            })
+            .collect();
+
+        let no_results = sections
+            .is_empty()
+            .then(|| widget::text::body(fl!("no-matching-settings")).into());
+
+        widget::settings::view_column(
+            std::iter::once(search.into())
+                .chain(sections)
+                .chain(no_results)
+                .collect(),
+        )
+        .into()
+    }
+
+    /// The status bar displaying the current status message, if there is one.
+    fn status_bar(&self) -> Option<Element<Message>> {
+        let cosmic_theme::Spacing { space_xs, .. } = theme::active().cosmic().spacing;
+
+        let (message, level, _) = self.status.as_ref()?;
+
+        let icon_name = match level {
+            StatusLevel::Info => "dialog-information-symbolic",
+            StatusLevel::Error => "dialog-error-symbolic",
+        };
+
+        let dismiss = widget::button::icon(icon::from_name("window-close-symbolic"))
+            .on_press(Message::DismissStatus);
+
+        widget::row()
+            .push(icon::from_name(icon_name).size(16).icon())
+            .push(widget::text::body(message.as_str()).width(Length::Fill))
+            .push(dismiss)
+            .align_y(Alignment::Center)
+            .spacing(space_xs)
+            .padding(space_xs)
+            .apply(widget::container)
+            .class(theme::Container::Card)
+            .width(Length::Fill)
+            .apply(Element::from)
+            .apply(Some)
+    }
+
+    /// Shows a message in the status bar, replacing the current one.
+    pub fn show_status(&mut self, level: StatusLevel, message: String) {
+        if level == StatusLevel::Error {
+            eprintln!("{message}");
+        }
+
+        self.status = Some((message, level, Instant::now()));
+    }
+
+    /// Reports an error which occurred while writing the config to disk.
+    fn config_save_failed(&mut self, why: cosmic_config::Error) {
+        self.show_status(
+            StatusLevel::Error,
+            fl!("config-save-error", error = why.to_string()),
+        );
+    }
+
+    /// Index of the configured startup page within the startup page options.
+    fn startup_page_index(&self) -> usize {
+        self.config
+            .startup_page
+            .and_then(|page| Page::ALL.iter().position(|&p| p == page))
+            .map_or(0, |i| i + 1)
+    }
+
+    /// Fills the nav bar with the pages that have not been hidden.
+    ///
+    /// The active page is kept if it's still visible, or else the first page is activated.
+    fn rebuild_nav(&mut self) {
+        let active = self.nav.active_data::<Page>().copied();
+
+        let mut visible = Page::ALL
+            .into_iter()
+            .filter(|page| !self.config.hidden_pages.contains(page))
+            .peekable();
+
+        // Hiding every page isn't allowed, but the config may have been edited by hand.
+        let visible: Vec<Page> = if visible.peek().is_some() {
+            visible.collect()
+        } else {
+            Page::ALL.to_vec()
+        };
+
+        self.nav.clear();
+
+        for page in visible {
+            self.nav
+                .insert()
+                .text(page.title())
+                .data::<Page>(page)
+                .icon(icon::from_name(page.icon_name()));
+        }
+
+        let id = active
+            .and_then(|page| self.page_id(page))
+            .or_else(|| self.nav.iter().next());
+
+        if let Some(id) = id {
+            self.nav.activate(id);
+        }
+    }
+
+    /// Finds the nav bar item which displays the given page.
+    fn page_id(&self, page: Page) -> Option<nav_bar::Id> {
+        self.nav
+            .iter()
+            .find(|&id| self.nav.data::<Page>(id) == Some(&page))
+    }
+
     /// Updates the header and window titles.
     pub fn update_title(&mut self) -> Task<Message> {
         let mut window_title = fl!("app-title");
@@ -279,19 +813,95 @@ impl AppModel {
             window_title.push_str(page);
         }
 
+        if let Some(clock) = self.config.title_clock.format(Local::now()) {
+            window_title.push_str(" — ");
+            window_title.push_str(&clock);
+        }
+
+        // The clock ticks every second, but the title changes at most once a minute.
+        if window_title == self.window_title {
+            return Task::none();
+        }
+
         if let Some(id) = self.core.main_window_id() {
+            self.window_title.clone_from(&window_title);
             self.set_window_title(window_title, id)
         } else {
             Task::none()
         }
     }
+
+    /// Applies the always on top setting to the main window.
+    ///
+    /// Wayland compositors may ignore window level requests from clients.
+    pub fn update_window_level(&self) -> Task<Message> {
+        let level = if self.config.always_on_top {
+            window::Level::AlwaysOnTop
+        } else {
+            window::Level::Normal
+        };
+
+        if let Some(id) = self.core.main_window_id() {
+            window::change_level(id, level)
+        } else {
+            Task::none()
+        }
+    }
+}
+
+impl Page {
+    /// The title shown for the page in the nav bar and menus.
+    pub fn title(self) -> String {
+        match self {
+            Page::Page1 => fl!("page-id", num = 1),
+            Page::Page2 => fl!("page-id", num = 2),
+            Page::Page3 => fl!("page-id", num = 3),
+        }
+    }
+
+    /// Empty state text shown on the page until it has content of its own.
+    pub fn empty_state(self) -> String {
+        match self {
+            Page::Page1 => fl!("page-1-empty"),
+            Page::Page2 => fl!("page-2-empty"),
+            Page::Page3 => fl!("page-3-empty"),
+        }
+    }
+
+    /// The name of the icon shown for the page in the nav bar.
+    pub fn icon_name(self) -> &'static str {
+        match self {
+            Page::Page1 => "applications-science-symbolic",
+            Page::Page2 => "applications-system-symbolic",
+            Page::Page3 => "applications-games-symbolic",
+        }
+    }
 }
 
-/// The page to display in the application.
-pub enum Page {
-    Page1,
-    Page2,
-    Page3,
+impl TitleClock {
+    /// The label shown for the option in the settings page.
+    pub fn title(self) -> String {
+        match self {
+            Self::Off => fl!("title-clock-off"),
+            Self::Time => fl!("title-clock-time"),
+            Self::Date => fl!("title-clock-date"),
+            Self::DateTime => fl!("title-clock-date-time"),
+        }
+    }
+
+    /// Formats the given time for display in the window title, if enabled.
+    pub fn format(self, now: DateTime<Local>) -> Option<String> {
+        match self {
+            Self::Off => None,
+            Self::Time => Some(i18n::format_time(&now)),
+            Self::Date => Some(i18n::format_date(&now)),
+            Self::DateTime => Some(format!(
+                "{} {}",
+                i18n::format_date(&now),
+                i18n::format_time(&now)
+            )),
+        }
+    }
 }
 
 /// The context page to display in the context drawer.
@@ -299,11 +909,28 @@ pub enum Page {
 pub enum ContextPage {
     #[default]
     About,
+    Settings,
+}
+
+/// The severity of a status message.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum StatusLevel {
+    Info,
+    Error,
+}
+
+/// The dialog to display over the application window.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DialogPage {
+    /// Confirm resetting the config to its defaults.
+    RestoreDefaults,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MenuAction {
     About,
+    SelectPage(Page),
+    Settings,
 }
 
 impl menu::action::MenuAction for MenuAction {
@@ -312,6 +939,27 @@ impl menu::action::MenuAction for MenuAction {
     fn message(&self) -> Self::Message {
         match self {
             MenuAction::About => Message::ToggleContextPage(ContextPage::About),
+            MenuAction::SelectPage(page) => Message::SelectPage(*page),
+            MenuAction::Settings => Message::ToggleContextPage(ContextPage::Settings),
         }
     }
 }
+
+/// Key bindings which trigger menu actions from anywhere in the application.
+fn key_binds() -> HashMap<menu::KeyBind, MenuAction> {
+    use menu::key_bind::Modifier;
+
+    let mut key_binds = HashMap::new();
+
+    for (num, page) in ["1", "2", "3"].into_iter().zip(Page::ALL) {
+        key_binds.insert(
+            menu::KeyBind {
+                modifiers: vec![Modifier::Ctrl],
+                key: Key::Character(num.into()),
+            },
+            MenuAction::SelectPage(page),
+        );
+    }
+
+    key_binds
+}
@@ -1,9 +1,49 @@
 // SPDX-License-Identifier: {{ license }}
 
 use cosmic::cosmic_config::{self, cosmic_config_derive::CosmicConfigEntry, CosmicConfigEntry};
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Clone, CosmicConfigEntry, Eq, PartialEq)]
 #[version = 1]
 pub struct Config {
-    demo: String,
+    /// Keep the main window above other windows.
+    pub always_on_top: bool,
+    /// The page to open on launch, or the last used page if `None`.
+    pub startup_page: Option<Page>,
+    /// The page that was active most recently.
+    pub last_page: Option<Page>,
+    /// Pages which are not shown in the nav bar.
+    pub hidden_pages: Vec<Page>,
+    /// The current time or date to include in the window title.
+    pub title_clock: TitleClock,
+    /// Show the current date at the end of the header bar.
+    pub header_date: bool,
+}
+
+/// The current time or date to include in the window title.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+pub enum TitleClock {
+    #[default]
+    Off,
+    Time,
+    Date,
+    DateTime,
+}
+
+impl TitleClock {
+    /// All options in the order they appear in the settings page.
+    pub const ALL: [Self; 4] = [Self::Off, Self::Time, Self::Date, Self::DateTime];
+}
+
+/// The page to display in the application.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize)]
+pub enum Page {
+    Page1,
+    Page2,
+    Page3,
+}
+
+impl Page {
+    /// All pages in the order they appear in the nav bar.
+    pub const ALL: [Self; 3] = [Self::Page1, Self::Page2, Self::Page3];
 }
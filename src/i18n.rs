@@ -2,8 +2,9 @@
 
 //! Provides localization support for this crate.
 
-use std::sync::LazyLock;
+use std::sync::{LazyLock, OnceLock};
 
+use chrono::{DateTime, Local, TimeZone, Utc};
 use i18n_embed::{
     fluent::{fluent_language_loader, FluentLanguageLoader},
     unic_langid::LanguageIdentifier,
@@ -16,6 +17,62 @@ pub fn init(requested_languages: &[LanguageIdentifier]) {
     if let Err(why) = localizer().select(requested_languages) {
         eprintln!("error while loading fluent localizations: {why}");
     }
+
+    // Use the first requested language which has locale data for dates and times.
+    // Languages without a region are tried with their most common one, such as `de_DE`.
+    let locale = requested_languages.iter().find_map(|language| {
+        let name = match language.region {
+            Some(region) => format!("{}_{region}", language.language),
+            None => format!(
+                "{}_{}",
+                language.language,
+                language.language.as_str().to_uppercase()
+            ),
+        };
+
+        chrono::Locale::try_from(name.as_str()).ok()
+    });
+
+    let time_format = match locale {
+        Some(locale) if uses_12_hour_clock(locale) => "%-I:%M %p",
+        _ => "%H:%M",
+    };
+
+    _ = LOCALE.set(locale);
+    _ = TIME_FORMAT.set(time_format);
+}
+
+/// Formats a date in the user's locale, or as an ISO 8601 date if no locale data is
+/// available.
+pub fn format_date(time: &DateTime<Local>) -> String {
+    match LOCALE.get().copied().flatten() {
+        Some(locale) => time.format_localized("%x", locale).to_string(),
+        None => time.format("%Y-%m-%d").to_string(),
+    }
+}
+
+/// Formats the hours and minutes of a time with the user's 12 or 24-hour clock.
+pub fn format_time(time: &DateTime<Local>) -> String {
+    let format = TIME_FORMAT.get().copied().unwrap_or("%H:%M");
+
+    match LOCALE.get().copied().flatten() {
+        Some(locale) => time.format_localized(format, locale).to_string(),
+        None => time.format(format).to_string(),
+    }
+}
+
+/// Whether the locale's time format uses a 12-hour clock.
+///
+/// Locales only define a time format with seconds, so check how it displays 13:00.
+fn uses_12_hour_clock(locale: chrono::Locale) -> bool {
+    let Some(afternoon) = Utc.with_ymd_and_hms(2000, 1, 1, 13, 0, 0).single() else {
+        return false;
+    };
+
+    !afternoon
+        .format_localized("%X", locale)
+        .to_string()
+        .contains("13")
 }
 
 // Get the `Localizer` to be used for localizing this library.
@@ -24,6 +81,12 @@ pub fn localizer() -> Box<dyn Localizer> {
     Box::from(DefaultLocalizer::new(&*LANGUAGE_LOADER, &Localizations))
 }
 
+/// Locale data for dates and times, set by [`init`] if any requested language has some.
+static LOCALE: OnceLock<Option<chrono::Locale>> = OnceLock::new();
+
+/// The `chrono` format string for hours and minutes in the locale, set by [`init`].
+static TIME_FORMAT: OnceLock<&str> = OnceLock::new();
+
 #[derive(RustEmbed)]
 #[folder = "i18n/"]
 struct Localizations;